@@ -7,11 +7,12 @@ use phf::phf_set;
 use scoped_tls::scoped_thread_local;
 use swc_atoms::{js_word, Atom};
 use swc_common::{
-    ast_node, util::take::Take, BytePos, EqIgnoreSpan, Span, Spanned, SyntaxContext, DUMMY_SP,
+    ast_node, util::take::Take, BytePos, EqIgnoreSpan, Mark, Span, Spanned, SyntaxContext,
+    DUMMY_SP,
 };
 use unicode_id::UnicodeID;
 
-use crate::{typescript::TsTypeAnn, Expr};
+use crate::{typescript::TsTypeAnn, EsVersion, Expr};
 
 /// Identifier used as a pattern.
 #[derive(Spanned, Clone, Debug, PartialEq, Eq, Hash, EqIgnoreSpan)]
@@ -66,6 +67,11 @@ impl BindingIdent {
     pub fn to_id(&self) -> Id {
         self.id.to_id()
     }
+
+    /// See [`Ident::gensym`] for documentation.
+    pub fn gensym(sym: Atom) -> BindingIdent {
+        Ident::gensym(sym).into()
+    }
 }
 
 impl Take for BindingIdent {
@@ -187,6 +193,26 @@ impl From<Ident> for Id {
     }
 }
 
+/// Options for [`Ident::verify_symbol_with`].
+///
+/// These let the reserved-word check respect the actual compilation
+/// target, instead of [`Ident::verify_symbol`]'s fixed behavior, which
+/// treats every symbol as if emitted into strict-mode module code and never
+/// escapes ES3-only reserved words.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VerifyOptions {
+    /// The target. Reserved-in-ES3-only words ([`IdentExt::is_reserved_in_es3`])
+    /// are only escaped when this is [`EsVersion::Es3`].
+    pub es_version: EsVersion,
+    /// Whether the symbol is emitted into strict-mode code. Strict-mode-only
+    /// reserved words ([`IdentExt::is_reserved_in_strict_mode`]) are only
+    /// escaped when this is set.
+    pub strict: bool,
+    /// Whether the symbol is emitted into a module, as opposed to a script.
+    /// `await` is only escaped when this is set, regardless of `strict`.
+    pub is_module: bool,
+}
+
 #[repr(C, align(64))]
 struct Align64<T>(pub(crate) T);
 
@@ -258,10 +284,30 @@ impl Ident {
     /// Returns [Ok] if it's a valid identifier and [Err] if it's not valid.
     /// The returned [Err] contains the valid symbol.
     pub fn verify_symbol(s: &str) -> Result<(), String> {
-        fn is_reserved_symbol(s: &str) -> bool {
+        Self::verify_symbol_impl(s, |s| {
             s.is_reserved() || s.is_reserved_in_strict_mode(true) || s.is_reserved_in_strict_bind()
-        }
+        })
+    }
 
+    /// Like [`Self::verify_symbol`], but the reserved-word check is tuned
+    /// for `options` instead of unconditionally assuming strict-mode module
+    /// code. For example, `int` is escaped when
+    /// [`VerifyOptions::es_version`] is [`EsVersion::Es3`], but passes
+    /// through untouched for ES2015+.
+    pub fn verify_symbol_with(s: &str, options: VerifyOptions) -> Result<(), String> {
+        Self::verify_symbol_impl(s, |s| {
+            s.is_reserved()
+                || s.is_reserved_in_strict_bind()
+                || (options.is_module && s == "await")
+                || (options.strict && s.is_reserved_in_strict_mode(options.is_module))
+                || (options.es_version == EsVersion::Es3 && s.is_reserved_in_es3())
+        })
+    }
+
+    fn verify_symbol_impl(
+        s: &str,
+        is_reserved_symbol: impl Fn(&str) -> bool,
+    ) -> Result<(), String> {
         if is_reserved_symbol(s) {
             let mut buf = String::with_capacity(s.len() + 1);
             buf.push('_');
@@ -312,6 +358,110 @@ impl Ident {
     pub fn is_dummy(&self) -> bool {
         self.sym == js_word!("") && self.span.is_dummy()
     }
+
+    /// Returns the [`SpecialIdent`] this identifier matches, if any.
+    pub fn special_kind(&self) -> Option<SpecialIdent> {
+        if self.is_dummy() {
+            Some(SpecialIdent::Empty)
+        } else if &*self.sym == "_" {
+            Some(SpecialIdent::Discard)
+        } else if self.sym.starts_with(INTERNAL_IDENT_PREFIX) {
+            Some(SpecialIdent::Internal)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if this identifier is special to swc itself, i.e. a
+    /// generated or placeholder name rather than one written by the user.
+    ///
+    /// This is a superset of [`Self::is_dummy`]: it also recognizes the `_`
+    /// discard binding and identifiers synthesized by swc's own passes
+    /// ([`SpecialIdent::Internal`]).
+    pub fn is_special(&self) -> bool {
+        self.special_kind().is_some()
+    }
+
+    /// Alias for [`Self::is_special`], for call sites that think of these
+    /// identifiers as placeholders rather than "special symbols".
+    pub fn is_placeholder(&self) -> bool {
+        self.is_special()
+    }
+
+    /// Creates an [`Ident`] for the given [`SpecialIdent`] kind, so that
+    /// transforms which inject placeholders can round-trip them reliably
+    /// via [`Self::special_kind`] instead of string-comparing `sym` against
+    /// literals scattered across the codebase.
+    ///
+    /// [`SpecialIdent::Discard`] and [`SpecialIdent::Internal`] each mint a
+    /// fresh [`SyntaxContext`] via [`Self::gensym`], so two placeholders of
+    /// the same kind (e.g. the two `_` elements of
+    /// `const [a, , , b] = x`) never collide on [`Self::to_id`]; use
+    /// [`Self::internal`] directly if you also need a descriptive name on
+    /// an internal placeholder.
+    ///
+    /// # Panics
+    ///
+    /// [`SpecialIdent::Discard`] and [`SpecialIdent::Internal`] go through
+    /// [`Self::gensym`], which requires an active `Globals` scope; see its
+    /// `# Panics` section. [`SpecialIdent::Empty`] never panics.
+    pub fn special(kind: SpecialIdent) -> Ident {
+        match kind {
+            SpecialIdent::Empty => Ident::dummy(),
+            SpecialIdent::Discard => Ident::gensym("_".into()),
+            SpecialIdent::Internal => Ident::internal(""),
+        }
+    }
+
+    /// Creates an identifier synthesized by one of swc's own passes, e.g. a
+    /// generated parameter name, with `name` appended to
+    /// [`INTERNAL_IDENT_PREFIX`] so it round-trips through
+    /// [`Self::special_kind`] as [`SpecialIdent::Internal`].
+    ///
+    /// Like [`Self::special`]`(`[`SpecialIdent::Internal`]`)`, which calls
+    /// this with an empty `name`, it mints a fresh [`SyntaxContext`] via
+    /// [`Self::gensym`], so two callers
+    /// synthesizing an "internal" placeholder with the same `name` still
+    /// produce distinct [`Id`]s instead of colliding on `to_id()`.
+    ///
+    /// See [`Self::gensym`]'s `# Panics` section for this function's (and,
+    /// transitively, [`Self::special`]'s) `Globals` precondition.
+    pub fn internal(name: impl Into<Atom>) -> Ident {
+        Ident::gensym(format!("{INTERNAL_IDENT_PREFIX}{}", name.into()).into())
+    }
+
+    /// Classifies [`Self::sym`] as a [`Keyword`], if it is one.
+    ///
+    /// See [`IdentExt::keyword_kind`] for the same predicate on any
+    /// `AsRef<str>`, e.g. a plain `&str` or [`Atom`] that hasn't been parsed
+    /// into an [`Ident`] yet.
+    pub fn as_keyword(&self) -> Option<Keyword> {
+        self.sym.keyword_kind()
+    }
+
+    /// Creates a fresh identifier with the given symbol that is guaranteed
+    /// not to collide with any other identifier in the module, including
+    /// ones that share the same `sym`.
+    ///
+    /// This is swc's equivalent of rustc's `Symbol::gensym` /
+    /// `Ident::with_empty_ctxt`: it mints a brand new [`SyntaxContext`] via
+    /// a fresh [`Mark`], so the resulting [`Id`] (`(Atom, SyntaxContext)`)
+    /// can't equal the [`Id`] of any identifier produced by the resolver or
+    /// by another `gensym` call. Passes that synthesize helper variables
+    /// should use this instead of hand-rolling a counter.
+    ///
+    /// # Panics
+    ///
+    /// Like every other `Mark`-minting API in swc, this reads and mutates
+    /// hygiene data owned by the ambient `Globals` (see
+    /// `swc_common::GLOBALS`). It must be called from within a
+    /// `GLOBALS.set(...)` scope; calling it with no `Globals` set (e.g. a
+    /// bare unit test, or a tool embedding swc without the usual
+    /// compiler-driver setup) panics.
+    pub fn gensym(sym: Atom) -> Ident {
+        let ctxt = SyntaxContext::empty().apply_mark(Mark::new());
+        Ident::new(sym, DUMMY_SP.with_ctxt(ctxt))
+    }
 }
 
 /// See [Ident] for documentation.
@@ -445,6 +595,80 @@ static RESERVED_IN_ES3: phf::Set<&str> = phf_set!(
     "volatile",
 );
 
+/// Words that are valid identifiers everywhere, but are reserved in specific
+/// grammatical positions: e.g. `async` before a function, `await`/`yield`
+/// inside an async function/generator, `let`/`static`/`of` in certain
+/// binding or loop positions, TS's `type` before an import specifier.
+///
+/// `yield`, `let` and `static` also appear in [`RESSERVED_IN_STRICT_MODE`],
+/// and [`IdentExt::is_reserved_in_strict_mode`] also reports `await` when
+/// `is_module` is set; [`Ident::verify_symbol_with`] and friends still
+/// consult that predicate directly
+/// for the strict-mode-escaping decision; [`IdentExt::keyword_kind`]
+/// classifies them as [`Keyword::Contextual`] regardless, matching how
+/// they actually behave in the grammar.
+static CONTEXTUAL: phf::Set<&str> = phf_set!(
+    "as",
+    "async",
+    "await",
+    "declare",
+    "from",
+    "get",
+    "let",
+    "meta",
+    "namespace",
+    "of",
+    "readonly",
+    "set",
+    "static",
+    "target",
+    "type",
+    "yield",
+);
+
+/// Classifies a symbol into the kind of keyword it is, if any.
+///
+/// This mirrors rustc's single authoritative keyword table: instead of
+/// querying a pile of ad-hoc boolean predicates, callers can ask "what kind
+/// of word is this?" once. See [`Ident::as_keyword`] and
+/// [`IdentExt::keyword_kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Keyword {
+    /// Reserved in every mode, e.g. `if`, `function`, `this`.
+    Reserved,
+    /// Reserved only in strict-mode code, e.g. `implements`, `interface`,
+    /// `private`.
+    StrictMode,
+    /// Reserved in ES3 but not in later editions, e.g. `int`, `abstract`.
+    FutureReservedEs3,
+    /// A valid identifier everywhere, but reserved in specific grammatical
+    /// positions, e.g. `async`, `await`, `yield`, `let`, `static`, `of`,
+    /// TS's `type`.
+    Contextual,
+}
+
+/// Prefix used to recognize an identifier synthesized by one of swc's own
+/// passes (e.g. a generated parameter name) as [`SpecialIdent::Internal`],
+/// analogous to rustc's `$crate` and `{{root}}`.
+const INTERNAL_IDENT_PREFIX: &str = "_swc_internal_";
+
+/// Identifiers that are special to swc itself, as opposed to one written by
+/// the user. Mirrors rustc's small set of special interned symbols (the
+/// empty symbol, `_`, `$crate`, `{{root}}`), so generated or placeholder
+/// names can be told apart from real ones. See [`Ident::special_kind`] and
+/// [`Ident::special`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpecialIdent {
+    /// The empty symbol, used by [`Ident::dummy`] and [`Ident::is_dummy`].
+    Empty,
+    /// The `_` discard binding, e.g. an unused destructuring element or
+    /// parameter.
+    Discard,
+    /// An identifier synthesized by one of swc's own passes, recognized by
+    /// the [`INTERNAL_IDENT_PREFIX`] prefix.
+    Internal,
+}
+
 pub trait IdentExt: AsRef<str> {
     fn is_reserved(&self) -> bool {
         RESERVED.contains(self.as_ref())
@@ -464,9 +688,136 @@ pub trait IdentExt: AsRef<str> {
     fn is_reserved_in_es3(&self) -> bool {
         RESERVED_IN_ES3.contains(self.as_ref())
     }
+
+    /// Classifies this symbol as a [`Keyword`], if it is one.
+    ///
+    /// `await`, `yield`, `let` and `static` are classified as
+    /// [`Keyword::Contextual`] here, matching how they actually behave in
+    /// the grammar (valid identifiers in general, reserved only in
+    /// specific positions); query [`Self::is_reserved_in_strict_mode`]
+    /// directly if you need the coarser "reserved in strict-mode code"
+    /// answer these words also satisfy.
+    fn keyword_kind(&self) -> Option<Keyword> {
+        let s = self.as_ref();
+
+        if self.is_reserved() {
+            Some(Keyword::Reserved)
+        } else if CONTEXTUAL.contains(s) {
+            Some(Keyword::Contextual)
+        } else if RESSERVED_IN_STRICT_MODE.contains(s) {
+            Some(Keyword::StrictMode)
+        } else if self.is_reserved_in_es3() {
+            Some(Keyword::FutureReservedEs3)
+        } else {
+            None
+        }
+    }
 }
 
 impl IdentExt for Atom {}
 impl IdentExt for Ident {}
 impl IdentExt for &'_ str {}
 impl IdentExt for String {}
+
+#[cfg(test)]
+mod tests {
+    use swc_common::{Globals, GLOBALS};
+
+    use super::*;
+
+    #[test]
+    fn keyword_kind_classifies_each_category() {
+        assert_eq!("if".keyword_kind(), Some(Keyword::Reserved));
+        assert_eq!("implements".keyword_kind(), Some(Keyword::StrictMode));
+        assert_eq!("int".keyword_kind(), Some(Keyword::FutureReservedEs3));
+
+        for contextual in [
+            "async", "await", "yield", "let", "static", "as", "from", "of", "get", "set",
+            "target", "meta", "type",
+        ] {
+            assert_eq!(
+                contextual.keyword_kind(),
+                Some(Keyword::Contextual),
+                "{contextual} should be classified as Contextual"
+            );
+        }
+
+        assert_eq!("foo".keyword_kind(), None);
+    }
+
+    #[test]
+    fn verify_symbol_with_respects_target() {
+        // `int` is only reserved when targeting ES3.
+        assert_eq!(
+            Ident::verify_symbol_with("int", VerifyOptions::default()),
+            Ok(())
+        );
+        assert!(Ident::verify_symbol_with(
+            "int",
+            VerifyOptions {
+                es_version: EsVersion::Es3,
+                ..Default::default()
+            }
+        )
+        .is_err());
+
+        // Strict-mode-only words are only escaped when `strict` is set.
+        assert_eq!(
+            Ident::verify_symbol_with("let", VerifyOptions::default()),
+            Ok(())
+        );
+        assert!(Ident::verify_symbol_with(
+            "let",
+            VerifyOptions {
+                strict: true,
+                ..Default::default()
+            }
+        )
+        .is_err());
+
+        // `await` is only escaped in module code, regardless of `strict`.
+        assert_eq!(
+            Ident::verify_symbol_with("await", VerifyOptions::default()),
+            Ok(())
+        );
+        assert!(Ident::verify_symbol_with(
+            "await",
+            VerifyOptions {
+                is_module: true,
+                ..Default::default()
+            }
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn gensym_produces_non_colliding_ids() {
+        GLOBALS.set(&Globals::new(), || {
+            let a = Ident::gensym(Atom::from("tmp"));
+            let b = Ident::gensym(Atom::from("tmp"));
+
+            assert_ne!(a.to_id(), b.to_id());
+        });
+    }
+
+    #[test]
+    fn special_kind_round_trips() {
+        assert_eq!(Ident::dummy().special_kind(), Some(SpecialIdent::Empty));
+        assert!(Ident::dummy().is_special());
+
+        assert_eq!(
+            Ident::new("_".into(), DUMMY_SP).special_kind(),
+            Some(SpecialIdent::Discard)
+        );
+
+        GLOBALS.set(&Globals::new(), || {
+            assert_eq!(
+                Ident::special(SpecialIdent::Internal).special_kind(),
+                Some(SpecialIdent::Internal)
+            );
+        });
+
+        assert_eq!(Ident::new("foo".into(), DUMMY_SP).special_kind(), None);
+        assert!(!Ident::new("foo".into(), DUMMY_SP).is_special());
+    }
+}